@@ -1,18 +1,120 @@
-use super::{Duration, Epoch};
+use super::{Duration, Epoch, Errors, Unit};
+#[cfg(feature = "std")]
+use core::str::FromStr;
 /*
 
 NOTE: This is taken from itertools: https://docs.rs/itertools-num/0.1.3/src/itertools_num/linspace.rs.html#78-93 .
 
 */
 
+/// A calendar-aware step for [`TimeSeries::calendar`], used when the cadence is expressed in
+/// variable-length units (months, years) that a fixed [`Duration`] cannot represent.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub enum CalendarStep {
+    Seconds(u32),
+    Minutes(u32),
+    Hours(u32),
+    Days(u32),
+    /// Advance by N months, clamping the day-of-month to the last valid day of the target
+    /// month (e.g. Jan 31 + 1 month = Feb 28/29, not Mar 3).
+    Months(u32),
+    /// Advance by N years, clamping Feb 29 to Feb 28 on non-leap target years.
+    Years(u32),
+}
+
+impl CalendarStep {
+    /// Moves `epoch` by `times` steps (negative to go backward) in a single jump. For `Months`
+    /// and `Years` the day-of-month is clamped once, at the target month, so the result can
+    /// differ from applying the step `times` times in a row.
+    fn advance_by(self, epoch: Epoch, times: i64) -> Epoch {
+        match self {
+            Self::Seconds(n) => epoch + times * i64::from(n) * Unit::Second,
+            Self::Minutes(n) => epoch + times * i64::from(n) * Unit::Minute,
+            Self::Hours(n) => epoch + times * i64::from(n) * Unit::Hour,
+            Self::Days(n) => epoch + times * i64::from(n) * Unit::Day,
+            Self::Months(n) => Self::add_months(epoch, times * i64::from(n)),
+            Self::Years(n) => Self::add_months(epoch, times * i64::from(n) * 12),
+        }
+    }
+
+    /// Decomposes `epoch` into its Gregorian components, adds `months` to the month/year
+    /// fields, clamps the day-of-month to the target month's length, and recomposes. Sub-day
+    /// components (hour, minute, second, nanos) are carried over unchanged.
+    fn add_months(epoch: Epoch, months: i64) -> Epoch {
+        let (year, month, day, hour, minute, second, nanos) = epoch.to_gregorian_utc();
+
+        let total_months = i64::from(month as i32 - 1) + months;
+        let year = year as i64 + total_months.div_euclid(12);
+        let month = (total_months.rem_euclid(12) + 1) as u8;
+        let day = day.min(days_in_month(year as i32, month));
+
+        Epoch::from_gregorian_utc(year as i32, month, day, hour, minute, second, nanos)
+    }
+}
+
+fn is_leap_year(year: i32) -> bool {
+    (year % 4 == 0 && year % 100 != 0) || (year % 400 == 0)
+}
+
+fn days_in_month(year: i32, month: u8) -> u8 {
+    match month {
+        1 | 3 | 5 | 7 | 8 | 10 | 12 => 31,
+        4 | 6 | 9 | 11 => 30,
+        2 => {
+            if is_leap_year(year) {
+                29
+            } else {
+                28
+            }
+        }
+        _ => unreachable!("invalid Gregorian month {month}"),
+    }
+}
+
+/// The cadence a [`TimeSeries`] advances `cur` by on each call to `next()`.
+#[derive(Clone, Debug)]
+enum Step {
+    Fixed(Duration),
+    Calendar(CalendarStep),
+}
+
+impl Step {
+    fn advance(&self, epoch: Epoch) -> Epoch {
+        self.advance_by(epoch, 1)
+    }
+
+    fn rewind(&self, epoch: Epoch) -> Epoch {
+        self.advance_by(epoch, -1)
+    }
+
+    /// Moves `epoch` by `times` steps (negative to go backward) in a single O(1) jump; see
+    /// `CalendarStep::advance_by` for how that differs from repeated steps on calendar series.
+    fn advance_by(&self, epoch: Epoch, times: i64) -> Epoch {
+        match self {
+            Self::Fixed(duration) => epoch + times * *duration,
+            Self::Calendar(calendar_step) => calendar_step.advance_by(epoch, times),
+        }
+    }
+}
+
 /// An iterator of a sequence of evenly spaced Epochs.
 #[derive(Clone, Debug)]
 pub struct TimeSeries {
     start: Epoch,
-    end: Epoch,
-    step: Duration,
+    end: Option<Epoch>,
+    step: Step,
     cur: Epoch,
+    /// Whether `next()` (or `nth()`/`advance_by()`) has emitted an item yet. Fixed-duration
+    /// series start `true` with `cur` rewound one step, since `Step::Fixed` is invertible and
+    /// advancing back from the rewind is exact. Calendar-stepped series start `false` with
+    /// `cur` set to `start` directly, because `CalendarStep::advance_by` clamps the day-of-month
+    /// and isn't invertible: rewinding and re-advancing a clamped step can land on a different
+    /// epoch than `start`.
+    started: bool,
     incl: bool,
+    /// Occurrences left to yield, for a count-bounded series (`TimeSeries::count`,
+    /// `TimeSeries::until_or_count`). `None` means the series is bounded by `end` alone.
+    remaining: Option<usize>,
 }
 
 impl TimeSeries {
@@ -32,13 +134,16 @@ impl TimeSeries {
     /// ```
     #[inline]
     pub fn exclusive(start: Epoch, end: Epoch, step: Duration) -> TimeSeries {
+        let step = Step::Fixed(step);
         // Start one step prior to start because next() just moves forward
         Self {
             start,
-            end,
+            end: Some(end),
+            cur: step.rewind(start),
             step,
-            cur: start - step,
+            started: true,
             incl: false,
+            remaining: None,
         }
     }
 
@@ -58,13 +163,203 @@ impl TimeSeries {
     /// ```
     #[inline]
     pub fn inclusive(start: Epoch, end: Epoch, step: Duration) -> TimeSeries {
+        let step = Step::Fixed(step);
         // Start one step prior to start because next() just moves forward
         Self {
             start,
-            end,
+            end: Some(end),
+            cur: step.rewind(start),
+            step,
+            started: true,
+            incl: true,
+            remaining: None,
+        }
+    }
+
+    /// Return an iterator of exactly `count` evenly spaced Epochs starting at `start`, with no
+    /// end bound. Useful for "repeat this event N times" schedules, where precomputing an end
+    /// epoch would otherwise be the caller's burden.
+    /// ```
+    /// use hifitime::{Epoch, Unit, TimeSeries};
+    /// let start = Epoch::from_gregorian_utc_at_midnight(2017, 1, 14);
+    /// let step = Unit::Day * 1;
+    /// let time_series = TimeSeries::count(start, step, 10);
+    /// assert_eq!(time_series.len(), 10);
+    /// assert_eq!(time_series.count(), 10);
+    /// ```
+    #[inline]
+    pub fn count(start: Epoch, step: Duration, count: usize) -> TimeSeries {
+        let step = Step::Fixed(step);
+        Self {
+            start,
+            end: None,
+            cur: step.rewind(start),
             step,
-            cur: start - step,
+            started: true,
             incl: true,
+            remaining: Some(count),
+        }
+    }
+
+    /// Return an iterator bounded by whichever of `end` or `count` occurrences comes first,
+    /// inclusive on start. This mirrors the RRULE model, where a recurrence may be bounded by
+    /// an end date *or* by a fixed occurrence count.
+    /// ```
+    /// use hifitime::{Epoch, Unit, TimeSeries};
+    /// let start = Epoch::from_gregorian_utc_at_midnight(2017, 1, 14);
+    /// let end = Epoch::from_gregorian_utc_at_midnight(2017, 1, 16);
+    /// let step = Unit::Day * 1;
+    /// // The end date is hit before the count of 100, so only 3 epochs come out.
+    /// let time_series = TimeSeries::until_or_count(start, end, step, 100);
+    /// assert_eq!(time_series.count(), 3);
+    /// ```
+    #[inline]
+    pub fn until_or_count(start: Epoch, end: Epoch, step: Duration, count: usize) -> TimeSeries {
+        let step = Step::Fixed(step);
+        Self {
+            start,
+            end: Some(end),
+            cur: step.rewind(start),
+            step,
+            started: true,
+            incl: true,
+            remaining: Some(count),
+        }
+    }
+
+    /// Return an iterator of Epochs advancing by a [`CalendarStep`] (e.g. every month, every
+    /// year), **inclusive** on start and **exclusive** on end. Unlike [`TimeSeries::exclusive`],
+    /// the step is not a fixed [`Duration`]: each call decomposes the current epoch into its
+    /// Gregorian components and adds months/years to them, clamping the day-of-month so that,
+    /// e.g., Jan 31 + 1 month lands on Feb 28/29 instead of overflowing into March.
+    /// ```
+    /// use hifitime::{Epoch, TimeSeries, CalendarStep};
+    /// let start = Epoch::from_gregorian_utc_at_midnight(2017, 1, 31);
+    /// let end = Epoch::from_gregorian_utc_at_midnight(2017, 4, 30);
+    /// let time_series = TimeSeries::calendar(start, end, CalendarStep::Months(1));
+    /// let months: Vec<Epoch> = time_series.collect();
+    /// // Jan 31 (the start) -> Feb 28 (clamped) -> Mar 28 -> Apr 28 (not Apr 30, because each
+    /// // step clamps from the previous month's already-clamped day-of-month).
+    /// assert_eq!(months.len(), 4);
+    /// ```
+    #[inline]
+    pub fn calendar(start: Epoch, end: Epoch, step: CalendarStep) -> TimeSeries {
+        let step = Step::Calendar(step);
+        Self {
+            start,
+            end: Some(end),
+            // Unlike the fixed-duration constructors, don't rewind: `CalendarStep::advance_by`
+            // clamps the day-of-month and isn't invertible, so rewinding and re-advancing could
+            // land on a different epoch than `start`. `started` tracks whether `start` itself
+            // has been emitted yet instead.
+            cur: start,
+            step,
+            started: false,
+            incl: false,
+            remaining: None,
+        }
+    }
+}
+
+impl TimeSeries {
+    /// The epoch `next()` would emit if called right now, without mutating any state.
+    fn pending(&self) -> Epoch {
+        if self.started {
+            self.step.advance(self.cur)
+        } else {
+            self.cur
+        }
+    }
+
+    /// Number of epochs still left to yield from the current position, taking whichever of the
+    /// end bound or the count bound is tighter. This is O(1) for fixed-duration steps (it's a
+    /// closed-form division, not a walk) and backs `len()`, `nth()`, `advance_by()` and `get()`.
+    fn steps_remaining(&self) -> usize {
+        let next_item = self.pending();
+        let by_end = self.end.map(|end| match &self.step {
+            Step::Fixed(duration) => {
+                let approx = ((end - next_item).in_seconds() / duration.in_seconds()).abs();
+                if self.incl {
+                    // `next_item` itself, plus every whole step that still lands on or before `end`.
+                    if next_item > end {
+                        0
+                    } else if approx.floor() >= usize::MAX as f64 {
+                        usize::MAX
+                    } else {
+                        approx.floor() as usize + 1
+                    }
+                } else {
+                    if approx.floor() >= usize::MAX as f64 {
+                        usize::MAX
+                    } else {
+                        approx.floor() as usize
+                    }
+                }
+            }
+            // Calendar steps are variable-length (a month is 28-31 days), so there's no closed
+            // form: count them by walking the series. Calendar-stepped series are expected to
+            // be small (billing cycles, monthly ephemerides), so this stays cheap in practice.
+            Step::Calendar(_) => self.clone().count(),
+        });
+
+        match (by_end, self.remaining) {
+            // Whichever bound is tighter wins, matching `next()`'s until-or-count behavior.
+            (Some(by_end), Some(remaining)) => by_end.min(remaining),
+            (Some(by_end), None) => by_end,
+            (None, Some(remaining)) => remaining,
+            (None, None) => unreachable!("a TimeSeries must have an end bound, a count bound, or both"),
+        }
+    }
+
+    /// Returns the k-th epoch (0-indexed) still to be yielded, counted from the iterator's
+    /// current position, without consuming the iterator, or `None` if `k` is out of range. On a
+    /// series that hasn't been advanced yet this is `start + k * step`. O(1) for fixed-duration
+    /// steps, since it jumps directly to that epoch instead of walking there. Calendar steps walk one step at a time
+    /// instead (see `nth`'s doc comment for why a direct jump doesn't work for those).
+    /// ```
+    /// use hifitime::{Epoch, Unit, TimeSeries};
+    /// let start = Epoch::from_gregorian_utc_at_midnight(2017, 1, 14);
+    /// let step = Unit::Day * 1;
+    /// let time_series = TimeSeries::count(start, step, 10);
+    /// assert_eq!(time_series.get(0), Some(start));
+    /// assert_eq!(time_series.get(9), Some(start + 9 * step));
+    /// assert_eq!(time_series.get(10), None);
+    /// ```
+    #[inline]
+    pub fn get(&self, k: usize) -> Option<Epoch> {
+        self.clone().nth(k)
+    }
+
+    /// Advances the iterator by `n` elements in O(1) (for fixed-duration steps) without
+    /// materializing them, by jumping `cur` directly instead of walking one step at a time.
+    /// Calendar steps can't take this shortcut (see `nth`'s doc comment) and fall back to
+    /// walking via `next()`. Mirrors the standard library's (currently unstable)
+    /// `Iterator::advance_by` contract: `Ok(())` if all `n` elements existed, or
+    /// `Err(remaining)` with the shortfall if the series was exhausted first, in which case
+    /// the iterator is left fully consumed.
+    pub fn advance_by(&mut self, n: usize) -> Result<(), usize> {
+        if matches!(self.step, Step::Calendar(_)) {
+            for i in 0..n {
+                if self.next().is_none() {
+                    return Err(n - i);
+                }
+            }
+            return Ok(());
+        }
+
+        let available = self.steps_remaining();
+        let advanced = n.min(available);
+        if advanced > 0 {
+            self.cur = self.step.advance_by(self.pending(), advanced as i64 - 1);
+            self.started = true;
+        }
+        if let Some(remaining) = self.remaining.as_mut() {
+            *remaining -= advanced;
+        }
+        if n > available {
+            Err(n - available)
+        } else {
+            Ok(())
         }
     }
 }
@@ -74,13 +369,65 @@ impl Iterator for TimeSeries {
 
     #[inline]
     fn next(&mut self) -> Option<Epoch> {
-        let next_item = self.cur + self.step;
-        if (!self.incl && next_item >= self.end) || (self.incl && next_item > self.end) {
-            None
-        } else {
-            self.cur = next_item;
-            Some(next_item)
+        if self.remaining == Some(0) {
+            return None;
         }
+
+        let next_item = self.pending();
+        if let Some(end) = self.end {
+            if (!self.incl && next_item >= end) || (self.incl && next_item > end) {
+                return None;
+            }
+        }
+
+        self.cur = next_item;
+        self.started = true;
+        if let Some(remaining) = self.remaining.as_mut() {
+            *remaining -= 1;
+        }
+        Some(next_item)
+    }
+
+    /// Jumps directly to the `n`-th next epoch in O(1) (for fixed-duration steps), instead of
+    /// the default `Iterator::nth` which would call `next()` n+1 times.
+    ///
+    /// `CalendarStep::advance_by`'s day-of-month clamping is cumulative across sequential
+    /// steps (each step clamps from the *previous*, already-clamped day, not from `start`),
+    /// so jumping directly to `start + n * step` does not reproduce what `n` calls to
+    /// `next()` would yield -- e.g. from Jan 31, three `next()` calls land on Jan 31, Feb 28,
+    /// Mar 28, but a direct 2-month jump from Jan 31 lands on Mar 31 (`days_in_month(3) == 31`
+    /// clamps nothing). So `Step::Calendar` series fall back to walking one step at a time.
+    #[inline]
+    fn nth(&mut self, n: usize) -> Option<Epoch> {
+        if matches!(self.step, Step::Calendar(_)) {
+            for _ in 0..n {
+                self.next()?;
+            }
+            return self.next();
+        }
+
+        let available = self.steps_remaining();
+        let next_item = self.pending();
+
+        if n >= available {
+            // Not enough epochs left: consume whatever remains and report None, same as the
+            // default `nth()` would after walking off the end.
+            if available > 0 {
+                self.cur = self.step.advance_by(next_item, available as i64 - 1);
+                self.started = true;
+            }
+            if let Some(remaining) = self.remaining.as_mut() {
+                *remaining = 0;
+            }
+            return None;
+        }
+
+        self.cur = self.step.advance_by(next_item, n as i64);
+        self.started = true;
+        if let Some(remaining) = self.remaining.as_mut() {
+            *remaining -= n + 1;
+        }
+        Some(self.cur)
     }
 
     fn size_hint(&self) -> (usize, Option<usize>) {
@@ -91,7 +438,7 @@ impl Iterator for TimeSeries {
 impl DoubleEndedIterator for TimeSeries {
     #[inline]
     fn next_back(&mut self) -> Option<Epoch> {
-        let next_item = self.cur - self.step;
+        let next_item = self.step.rewind(self.cur);
         if next_item < self.start {
             None
         } else {
@@ -105,26 +452,248 @@ where
     TimeSeries: Iterator,
 {
     fn len(&self) -> usize {
-        let approx = ((self.end - self.start).in_seconds() / self.step.in_seconds()).abs();
-        if self.incl {
-            if approx.ceil() >= usize::MAX as f64 {
-                usize::MAX
-            } else {
-                approx.ceil() as usize
+        self.steps_remaining()
+    }
+}
+
+/// Reasons why [`TimeSeries::from_str`] can fail to parse a recurrence spec of the form
+/// `"<anchor> <cadence> [until <end>|times <n>]"`.
+#[cfg(feature = "std")]
+#[derive(Clone, Debug, PartialEq)]
+pub enum TimeSeriesError {
+    /// The anchor epoch isn't a valid Gregorian datetime.
+    Anchor(Errors),
+    /// The cadence keyword isn't one of `secondly`, `minutely`, `hourly`, `daily`, `weekly`.
+    UnknownCadence,
+    /// The `until <end>` epoch isn't a valid Gregorian datetime.
+    Until(Errors),
+    /// The `times <n>` bound isn't a valid non-negative integer.
+    InvalidCount,
+    /// The spec doesn't match `<anchor> <cadence> [until <end>|times <n>]`.
+    Malformed,
+}
+
+#[cfg(feature = "std")]
+impl std::fmt::Display for TimeSeriesError {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match self {
+            Self::Anchor(e) => write!(f, "invalid anchor epoch: {e}"),
+            Self::UnknownCadence => write!(
+                f,
+                "unknown cadence keyword (expected one of: secondly, minutely, hourly, daily, weekly)"
+            ),
+            Self::Until(e) => write!(f, "invalid `until` epoch: {e}"),
+            Self::InvalidCount => write!(f, "invalid `times` count"),
+            Self::Malformed => {
+                write!(f, "expected `<anchor> <cadence> [until <end>|times <n>]`")
             }
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for TimeSeriesError {}
+
+#[cfg(feature = "std")]
+impl FromStr for TimeSeries {
+    type Err = TimeSeriesError;
+
+    /// Parses specs like `"2017-01-14T00:00:00 UTC daily until 2017-01-20T00:00:00 UTC"` or
+    /// `"2017-01-14T00:00:00 UTC hourly times 5"` into a `TimeSeries`. The cadence keyword
+    /// (`secondly`, `minutely`, `hourly`, `daily`, `weekly`) resolves to the matching `Unit`
+    /// step, and the trailing `until <epoch>` / `times <n>` resolves to an inclusive end bound
+    /// or a count bound, reusing `Epoch::from_gregorian_str` for the anchor and end dates.
+    /// ```
+    /// use hifitime::TimeSeries;
+    /// let time_series: TimeSeries =
+    ///     "2017-01-14T00:00:00 UTC daily until 2017-01-20T00:00:00 UTC".parse().unwrap();
+    /// assert_eq!(time_series.count(), 7);
+    ///
+    /// let time_series: TimeSeries = "2017-01-14T00:00:00 UTC hourly times 5".parse().unwrap();
+    /// assert_eq!(time_series.count(), 5);
+    /// ```
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut words = s.split_whitespace();
+
+        let date = words.next().ok_or(TimeSeriesError::Malformed)?;
+        let tz = words.next().ok_or(TimeSeriesError::Malformed)?;
+        let start = Epoch::from_gregorian_str(&format!("{date} {tz}"))
+            .map_err(TimeSeriesError::Anchor)?;
+
+        let cadence = words.next().ok_or(TimeSeriesError::Malformed)?;
+        let step = if cadence.eq_ignore_ascii_case("secondly") {
+            Unit::Second * 1
+        } else if cadence.eq_ignore_ascii_case("minutely") {
+            Unit::Minute * 1
+        } else if cadence.eq_ignore_ascii_case("hourly") {
+            Unit::Hour * 1
+        } else if cadence.eq_ignore_ascii_case("daily") {
+            Unit::Day * 1
+        } else if cadence.eq_ignore_ascii_case("weekly") {
+            Unit::Week * 1
         } else {
-            if approx.floor() >= usize::MAX as f64 {
-                usize::MAX
-            } else {
-                approx.floor() as usize
+            return Err(TimeSeriesError::UnknownCadence);
+        };
+
+        match words.next() {
+            Some(keyword) if keyword.eq_ignore_ascii_case("until") => {
+                let date = words.next().ok_or(TimeSeriesError::Malformed)?;
+                let tz = words.next().ok_or(TimeSeriesError::Malformed)?;
+                let end = Epoch::from_gregorian_str(&format!("{date} {tz}"))
+                    .map_err(TimeSeriesError::Until)?;
+                Ok(TimeSeries::inclusive(start, end, step))
+            }
+            Some(keyword) if keyword.eq_ignore_ascii_case("times") => {
+                let n = words
+                    .next()
+                    .ok_or(TimeSeriesError::Malformed)?
+                    .parse()
+                    .map_err(|_| TimeSeriesError::InvalidCount)?;
+                Ok(TimeSeries::count(start, step, n))
+            }
+            _ => Err(TimeSeriesError::Malformed),
+        }
+    }
+}
+
+/// Iterator returned by [`TimeSeries::without`], skipping any epoch within `tolerance` of one
+/// of the excluded epochs.
+///
+/// Requires `std` (as does the `FromStr` parser, unlike `TimeSeries` and [`Merge`] themselves):
+/// it collects the exclusions into a `Vec`,
+/// which isn't available in a `no_std` build (this crate has no `alloc`-only feature to pull
+/// in a global allocator without the rest of `std`).
+#[cfg(feature = "std")]
+#[derive(Clone, Debug)]
+pub struct WithoutExclusions {
+    inner: TimeSeries,
+    /// Sorted ascending, so `cursor` only ever moves forward.
+    exclusions: Vec<Epoch>,
+    /// Index of the first exclusion that could still be within `tolerance` of the current (or a
+    /// later) candidate. Exclusions before this index are more than `tolerance` behind every
+    /// future candidate, since `inner` yields epochs in increasing order.
+    cursor: usize,
+    tolerance: Duration,
+}
+
+#[cfg(feature = "std")]
+impl Iterator for WithoutExclusions {
+    type Item = Epoch;
+
+    fn next(&mut self) -> Option<Epoch> {
+        loop {
+            let candidate = self.inner.next()?;
+            while self.cursor < self.exclusions.len()
+                && self.exclusions[self.cursor] + self.tolerance < candidate
+            {
+                self.cursor += 1;
             }
+            let excluded = match self.exclusions.get(self.cursor) {
+                Some(&excluded) => (candidate - excluded).abs() <= self.tolerance,
+                None => false,
+            };
+            if !excluded {
+                return Some(candidate);
+            }
+        }
+    }
+}
+
+/// Iterator returned by [`TimeSeries::merge`], yielding the time-ordered union of two series
+/// and deduplicating coincident epochs (within `tolerance`) in favor of the left series.
+#[derive(Clone, Debug)]
+pub struct Merge {
+    left: core::iter::Peekable<TimeSeries>,
+    right: core::iter::Peekable<TimeSeries>,
+    tolerance: Duration,
+}
+
+impl Iterator for Merge {
+    type Item = Epoch;
+
+    fn next(&mut self) -> Option<Epoch> {
+        match (self.left.peek(), self.right.peek()) {
+            (Some(&left), Some(&right)) => {
+                if (left - right).abs() <= self.tolerance {
+                    // Coincident: yield the left epoch and drop the right's duplicate.
+                    self.right.next();
+                    self.left.next()
+                } else if left < right {
+                    self.left.next()
+                } else {
+                    self.right.next()
+                }
+            }
+            (Some(_), None) => self.left.next(),
+            (None, Some(_)) => self.right.next(),
+            (None, None) => None,
+        }
+    }
+}
+
+impl TimeSeries {
+    /// Wraps this series to skip any generated epoch within `tolerance` of one of
+    /// `exclusions` (e.g. holidays, maintenance windows). Stays lazy: each epoch is checked
+    /// against the exclusion list as it's pulled, with no intermediate `Vec` of the full series.
+    /// The exclusions are sorted once up front so the check walks a cursor forward instead of
+    /// rescanning the whole list for every candidate epoch.
+    /// ```
+    /// use hifitime::{Epoch, TimeSeries, Unit};
+    /// let start = Epoch::from_gregorian_utc_at_midnight(2017, 1, 14);
+    /// let end = Epoch::from_gregorian_utc_at_midnight(2017, 1, 19);
+    /// let time_series = TimeSeries::exclusive(start, end, Unit::Day * 1);
+    /// let holiday = start + 2 * Unit::Day;
+    /// let epochs: Vec<Epoch> = time_series.without([holiday], Unit::Hour * 1).collect();
+    /// assert_eq!(epochs.len(), 4);
+    /// assert!(!epochs.contains(&holiday));
+    /// ```
+    #[cfg(feature = "std")]
+    pub fn without(
+        self,
+        exclusions: impl IntoIterator<Item = Epoch>,
+        tolerance: Duration,
+    ) -> WithoutExclusions {
+        let mut exclusions: Vec<Epoch> = exclusions.into_iter().collect();
+        exclusions.sort();
+        WithoutExclusions {
+            inner: self,
+            exclusions,
+            cursor: 0,
+            tolerance,
+        }
+    }
+
+    /// Returns the time-ordered union of this series and `other`, deduplicating epochs that
+    /// land within `tolerance` of each other (keeping the occurrence from `self`). Stays lazy,
+    /// advancing a two-way merge cursor instead of collecting either series first.
+    /// ```
+    /// use hifitime::{Epoch, TimeSeries, Unit};
+    /// let daily = TimeSeries::count(
+    ///     Epoch::from_gregorian_utc_at_midnight(2017, 1, 14),
+    ///     Unit::Day * 1,
+    ///     3,
+    /// );
+    /// let weekly = TimeSeries::count(
+    ///     Epoch::from_gregorian_utc_at_midnight(2017, 1, 14),
+    ///     Unit::Week * 1,
+    ///     2,
+    /// );
+    /// // The first epoch of both series coincides, so the union has 3 + 2 - 1 epochs.
+    /// let merged: Vec<Epoch> = daily.merge(weekly, Unit::Second * 1).collect();
+    /// assert_eq!(merged.len(), 4);
+    /// ```
+    pub fn merge(self, other: TimeSeries, tolerance: Duration) -> Merge {
+        Merge {
+            left: self.peekable(),
+            right: other.peekable(),
+            tolerance,
         }
     }
 }
 
 #[cfg(test)]
 mod tests {
-    use crate::{Epoch, TimeSeries, Unit};
+    use crate::{CalendarStep, Epoch, TimeSeries, Unit};
 
     #[test]
     fn test_timeseries() {
@@ -169,6 +738,193 @@ mod tests {
         assert_eq!(count, 7, "Should have six items in this iterator");
     }
 
+    #[test]
+    fn test_count() {
+        let start = Epoch::from_gregorian_utc_at_midnight(2017, 1, 14);
+        let step = Unit::Day * 1;
+
+        let time_series = TimeSeries::count(start, step, 10);
+        assert_eq!(time_series.len(), 10);
+        assert_eq!(time_series.size_hint().0, 10);
+
+        let epochs: Vec<Epoch> = time_series.collect();
+        assert_eq!(epochs.len(), 10, "count() should yield exactly n epochs");
+        assert_eq!(epochs[0], start);
+        assert_eq!(epochs[9], start + 9 * step);
+    }
+
+    #[test]
+    fn test_until_or_count_end_first() {
+        let start = Epoch::from_gregorian_utc_at_midnight(2017, 1, 14);
+        let end = Epoch::from_gregorian_utc_at_midnight(2017, 1, 16);
+        let step = Unit::Day * 1;
+
+        // The end date (2 days out) is hit before the count (100), so the end wins.
+        let time_series = TimeSeries::until_or_count(start, end, step, 100);
+        assert_eq!(time_series.len(), 3);
+        assert_eq!(time_series.count(), 3);
+    }
+
+    #[test]
+    fn test_until_or_count_count_first() {
+        let start = Epoch::from_gregorian_utc_at_midnight(2017, 1, 14);
+        let end = Epoch::from_gregorian_utc_at_midnight(2017, 2, 1);
+        let step = Unit::Day * 1;
+
+        // The count (3) is hit before the end date (18 days out), so the count wins.
+        let time_series = TimeSeries::until_or_count(start, end, step, 3);
+        assert_eq!(time_series.len(), 3);
+        assert_eq!(time_series.count(), 3);
+    }
+
+    #[test]
+    fn test_nth() {
+        let start = Epoch::from_gregorian_utc_at_midnight(2017, 1, 14);
+        let step = Unit::Day * 1;
+
+        let mut time_series = TimeSeries::count(start, step, 10);
+        assert_eq!(time_series.nth(2), Some(start + 2 * step));
+        // nth() consumes the skipped elements too, so the next item continues from there.
+        assert_eq!(time_series.next(), Some(start + 3 * step));
+
+        // Asking for more than remain yields None and leaves the iterator exhausted.
+        let mut time_series = TimeSeries::count(start, step, 10);
+        assert_eq!(time_series.nth(100), None);
+        assert_eq!(time_series.next(), None);
+    }
+
+    #[test]
+    fn test_advance_by_and_get() {
+        let start = Epoch::from_gregorian_utc_at_midnight(2017, 1, 14);
+        let step = Unit::Day * 1;
+
+        let time_series = TimeSeries::count(start, step, 10);
+        assert_eq!(time_series.get(0), Some(start));
+        assert_eq!(time_series.get(4), Some(start + 4 * step));
+        assert_eq!(time_series.get(9), Some(start + 9 * step));
+        assert_eq!(time_series.get(10), None);
+
+        let mut time_series = TimeSeries::count(start, step, 10);
+        assert_eq!(time_series.advance_by(4), Ok(()));
+        assert_eq!(time_series.next(), Some(start + 4 * step));
+
+        let mut time_series = TimeSeries::count(start, step, 10);
+        assert_eq!(time_series.advance_by(100), Err(90));
+        assert_eq!(time_series.next(), None);
+    }
+
+    #[test]
+    fn test_nth_advance_by_get_with_end_bound() {
+        // Unlike `test_nth`/`test_advance_by_and_get` above, this series is bounded by `end`
+        // rather than by a count, which exercises the `Step::Fixed` branch of
+        // `steps_remaining()`.
+        let start = Epoch::from_gregorian_utc_at_midnight(2017, 1, 14);
+        let end = Epoch::from_gregorian_utc_at_midnight(2017, 1, 24);
+        let step = Unit::Day * 1;
+
+        // 10 days apart stepping by 1 day, exclusive of `end`, is exactly 10 epochs.
+        let time_series = TimeSeries::exclusive(start, end, step);
+        assert_eq!(time_series.len(), 10);
+        assert_eq!(time_series.get(0), Some(start));
+        assert_eq!(time_series.get(9), Some(start + 9 * step));
+        assert_eq!(time_series.get(10), None);
+
+        let mut time_series = TimeSeries::exclusive(start, end, step);
+        assert_eq!(time_series.nth(9), Some(start + 9 * step));
+        // The series is now exhausted: the excluded `end` epoch must never surface.
+        assert_eq!(time_series.next(), None);
+
+        let mut time_series = TimeSeries::exclusive(start, end, step);
+        assert_eq!(time_series.advance_by(5), Ok(()));
+        assert_eq!(time_series.next(), Some(start + 5 * step));
+    }
+
+    #[test]
+    #[cfg(feature = "std")]
+    fn test_from_str_until() {
+        let time_series: TimeSeries = "2017-01-14T00:00:00 UTC daily until 2017-01-20T00:00:00 UTC"
+            .parse()
+            .unwrap();
+        assert_eq!(
+            time_series.start,
+            Epoch::from_gregorian_utc_at_midnight(2017, 1, 14)
+        );
+        assert_eq!(time_series.count(), 7);
+    }
+
+    #[test]
+    #[cfg(feature = "std")]
+    fn test_from_str_times() {
+        let time_series: TimeSeries = "2017-01-14T00:00:00 UTC hourly times 5".parse().unwrap();
+        assert_eq!(time_series.count(), 5);
+
+        let time_series: TimeSeries = "2017-01-14T00:00:00 UTC weekly times 3".parse().unwrap();
+        let epochs: Vec<Epoch> = time_series.collect();
+        assert_eq!(epochs.len(), 3);
+        assert_eq!(epochs[1], epochs[0] + Unit::Week * 1);
+    }
+
+    #[test]
+    #[cfg(feature = "std")]
+    fn test_from_str_errors() {
+        use super::TimeSeriesError;
+
+        assert!(matches!(
+            "not a date daily times 5".parse::<TimeSeries>(),
+            Err(TimeSeriesError::Anchor(_))
+        ));
+        assert!(matches!(
+            "2017-01-14T00:00:00 UTC fortnightly times 5".parse::<TimeSeries>(),
+            Err(TimeSeriesError::UnknownCadence)
+        ));
+        assert!(matches!(
+            "2017-01-14T00:00:00 UTC daily times not-a-number".parse::<TimeSeries>(),
+            Err(TimeSeriesError::InvalidCount)
+        ));
+        assert!(matches!(
+            "2017-01-14T00:00:00 UTC daily".parse::<TimeSeries>(),
+            Err(TimeSeriesError::Malformed)
+        ));
+    }
+
+    #[test]
+    #[cfg(feature = "std")]
+    fn test_without_exclusions() {
+        let start = Epoch::from_gregorian_utc_at_midnight(2017, 1, 14);
+        let end = Epoch::from_gregorian_utc_at_midnight(2017, 1, 19);
+        let time_series = TimeSeries::exclusive(start, end, Unit::Day * 1);
+
+        let holiday = start + 2 * Unit::Day;
+        let epochs: Vec<Epoch> = time_series.without([holiday], Unit::Hour * 1).collect();
+        assert_eq!(epochs.len(), 4, "should skip exactly the excluded epoch");
+        assert!(!epochs.contains(&holiday));
+
+        // A tolerance wide enough to also catch a near-miss excludes that one too.
+        let time_series = TimeSeries::exclusive(start, end, Unit::Day * 1);
+        let near_miss = start + 2 * Unit::Day + 10 * Unit::Minute;
+        let epochs: Vec<Epoch> = time_series
+            .without([near_miss], Unit::Hour * 1)
+            .collect();
+        assert_eq!(epochs.len(), 4);
+    }
+
+    #[test]
+    fn test_merge() {
+        let start = Epoch::from_gregorian_utc_at_midnight(2017, 1, 14);
+
+        let daily = TimeSeries::count(start, Unit::Day * 1, 3);
+        let weekly = TimeSeries::count(start, Unit::Week * 1, 2);
+
+        // The first epoch of both series coincides, so the union has 3 + 2 - 1 epochs.
+        let merged: Vec<Epoch> = daily.merge(weekly, Unit::Second * 1).collect();
+        assert_eq!(merged.len(), 4);
+
+        // The result is time-ordered.
+        for window in merged.windows(2) {
+            assert!(window[0] < window[1]);
+        }
+    }
+
     #[test]
     fn gh131_regression() {
         let start = Epoch::from_gregorian_str("2022-07-14T02:56:11.228271007 UTC").unwrap();
@@ -185,4 +941,68 @@ mod tests {
         assert_eq!(times.len(), steps as usize);
         assert_eq!(times.len(), times.size_hint().0);
     }
+
+    #[test]
+    fn test_calendar_month_end_clamping() {
+        // Jan 31 + 1 month should clamp to Feb 28 (2017 is not a leap year), not overflow
+        // into March.
+        let start = Epoch::from_gregorian_utc_at_midnight(2017, 1, 31);
+        let end = Epoch::from_gregorian_utc_at_midnight(2017, 12, 31);
+        let mut time_series = TimeSeries::calendar(start, end, CalendarStep::Months(1));
+
+        // `calendar()` is inclusive on start, so the first item is `start` itself.
+        assert_eq!(time_series.next(), Some(start));
+
+        let (year, month, day, _, _, _, _) = time_series.next().unwrap().to_gregorian_utc();
+        assert_eq!((year, month, day), (2017, 2, 28));
+    }
+
+    #[test]
+    fn test_calendar_leap_year() {
+        // Feb 29, 2020 (leap year) + 1 year should clamp to Feb 28, 2021 (non-leap).
+        let start = Epoch::from_gregorian_utc_at_midnight(2020, 2, 29);
+        let end = Epoch::from_gregorian_utc_at_midnight(2022, 1, 1);
+        let mut time_series = TimeSeries::calendar(start, end, CalendarStep::Years(1));
+
+        assert_eq!(time_series.next(), Some(start));
+
+        let (year, month, day, _, _, _, _) = time_series.next().unwrap().to_gregorian_utc();
+        assert_eq!((year, month, day), (2021, 2, 28));
+    }
+
+    #[test]
+    fn test_calendar_preserves_time_of_day() {
+        let start = Epoch::from_gregorian_utc(2017, 1, 15, 8, 30, 15, 42);
+        let end = Epoch::from_gregorian_utc(2017, 6, 15, 8, 30, 15, 42);
+        let mut time_series = TimeSeries::calendar(start, end, CalendarStep::Months(1));
+
+        // Skip `start` itself and check the first actual calendar step.
+        assert_eq!(time_series.next(), Some(start));
+
+        let (_, _, _, hour, minute, second, nanos) = time_series.next().unwrap().to_gregorian_utc();
+        assert_eq!((hour, minute, second, nanos), (8, 30, 15, 42));
+    }
+
+    #[test]
+    fn test_calendar_nth_advance_by_get() {
+        // CalendarStep's day-of-month clamping is cumulative across steps (each step clamps
+        // from the previous, already-clamped day), so nth()/advance_by()/get() must walk one
+        // step at a time instead of jumping straight to `start + k * step` the way the
+        // Step::Fixed path does. A direct 2-month jump from Jan 31 would land on Mar 31
+        // (`days_in_month(3) == 31` clamps nothing), but three sequential `next()` calls
+        // yield Jan 31 -> Feb 28 -> Mar 28.
+        let start = Epoch::from_gregorian_utc_at_midnight(2017, 1, 31);
+        let end = Epoch::from_gregorian_utc_at_midnight(2018, 1, 31);
+        let mar_28 = Epoch::from_gregorian_utc_at_midnight(2017, 3, 28);
+
+        let time_series = TimeSeries::calendar(start, end, CalendarStep::Months(1));
+        assert_eq!(time_series.get(2), Some(mar_28));
+
+        let mut time_series = TimeSeries::calendar(start, end, CalendarStep::Months(1));
+        assert_eq!(time_series.nth(2), Some(mar_28));
+
+        let mut time_series = TimeSeries::calendar(start, end, CalendarStep::Months(1));
+        assert_eq!(time_series.advance_by(2), Ok(()));
+        assert_eq!(time_series.next(), Some(mar_28));
+    }
 }